@@ -17,9 +17,86 @@ use super::FfiHandle;
 use crate::{proto, server, FfiError, FfiHandleId, FfiResult};
 use futures_util::StreamExt;
 use livekit::webrtc::prelude::*;
+use livekit::webrtc::video_frame::{BoxVideoFrameBuffer, I420Buffer};
 use livekit::webrtc::video_stream::native::NativeVideoStream;
 use tokio::sync::oneshot;
 
+/// Target output requested for a native video stream: a pixel format, a bounding size, or both.
+/// A `None` size bound is derived from the other bound (or the source size) by aspect ratio; a
+/// `None` format yields I420.
+#[derive(Clone, Copy)]
+struct VideoStreamTarget {
+    format: Option<proto::VideoBufferType>,
+    width: Option<u32>,
+    height: Option<u32>,
+}
+
+impl VideoStreamTarget {
+    // `format`/`width`/`height` need to land directly on `NewVideoStreamRequest` in the real
+    // upstream `protocol/video_frame.proto` (protobuf doesn't support splitting one message's
+    // fields across two .proto files); assumed present here until that lands upstream.
+    fn from_request(new_stream: &proto::NewVideoStreamRequest) -> Option<Self> {
+        let format = new_stream.format.map(|f| f.try_into().unwrap_or(proto::VideoBufferType::I420));
+        let target = Self { format, width: new_stream.width, height: new_stream.height };
+        (target.format.is_some() || target.width.is_some() || target.height.is_some())
+            .then_some(target)
+    }
+}
+
+/// Scales `(src_width, src_height)` down/up to fit inside `(max_width, max_height)` while
+/// preserving the source aspect ratio. With only one bound, the other dimension is derived from
+/// it by the source aspect ratio; with neither, the source dimensions are returned unchanged.
+fn scale_preserving_aspect(
+    src_width: u32,
+    src_height: u32,
+    max_width: Option<u32>,
+    max_height: Option<u32>,
+) -> (u32, u32) {
+    let (max_width, max_height) = match (max_width, max_height) {
+        (Some(w), Some(h)) => (w, h),
+        (Some(w), None) => return (w, (w * src_height) / src_width.max(1)),
+        (None, Some(h)) => return ((h * src_width) / src_height.max(1), h),
+        (None, None) => return (src_width, src_height),
+    };
+
+    // Both bounds given: fit the source ratio inside the (max_width, max_height) box rather than
+    // stretching to it.
+    let src_ratio = src_width as f64 / src_height.max(1) as f64;
+    let dst_ratio = max_width as f64 / max_height.max(1) as f64;
+    if src_ratio > dst_ratio {
+        (max_width, ((max_width as f64) / src_ratio).round() as u32)
+    } else {
+        (((max_height as f64) * src_ratio).round() as u32, max_height)
+    }
+}
+
+/// Converts/scales a decoded frame buffer according to `target`, so every FFI binding receives
+/// frames in the same predictable layout instead of re-implementing I420<->RGBA/NV12 conversion
+/// on the hot path. Always converts; the stream task skips this call (pass-through) when no
+/// target was requested.
+fn convert_frame_buffer(buffer: BoxVideoFrameBuffer, target: VideoStreamTarget) -> BoxVideoFrameBuffer {
+    let (dst_width, dst_height) =
+        scale_preserving_aspect(buffer.width(), buffer.height(), target.width, target.height);
+
+    let i420: I420Buffer = buffer.to_i420();
+    let i420 = if (dst_width, dst_height) != (i420.width(), i420.height()) {
+        i420.scale(dst_width, dst_height)
+    } else {
+        i420
+    };
+
+    match target.format.unwrap_or(proto::VideoBufferType::I420) {
+        proto::VideoBufferType::I420 => Box::new(i420),
+        proto::VideoBufferType::Nv12 => Box::new(i420.to_nv12()),
+        proto::VideoBufferType::Rgba => Box::new(i420.to_rgba()),
+        proto::VideoBufferType::Bgra => Box::new(i420.to_bgra()),
+        other => {
+            log::warn!("unsupported target video format {:?}, falling back to I420", other);
+            Box::new(i420)
+        }
+    }
+}
+
 pub struct FfiVideoStream {
     pub handle_id: FfiHandleId,
     pub stream_type: proto::VideoStreamType,
@@ -52,6 +129,7 @@ impl FfiVideoStream {
 
         let (close_tx, close_rx) = oneshot::channel();
         let stream_type = new_stream.r#type();
+        let target = VideoStreamTarget::from_request(&new_stream);
         let handle_id = server.next_id();
         let stream = match stream_type {
             #[cfg(not(target_arch = "wasm32"))]
@@ -66,6 +144,7 @@ impl FfiVideoStream {
                     handle_id,
                     NativeVideoStream::new(rtc_track),
                     close_rx,
+                    target,
                 ));
                 Ok::<FfiVideoStream, FfiError>(video_stream)
             }
@@ -91,6 +170,7 @@ impl FfiVideoStream {
         stream_handle: FfiHandleId,
         mut native_stream: NativeVideoStream,
         mut close_rx: oneshot::Receiver<()>,
+        target: Option<VideoStreamTarget>,
     ) {
         loop {
             tokio::select! {
@@ -98,10 +178,14 @@ impl FfiVideoStream {
                     break;
                 }
                 frame = native_stream.next() => {
-                    let Some(frame) = frame else {
+                    let Some(mut frame) = frame else {
                         break;
                     };
 
+                    if let Some(target) = target {
+                        frame.buffer = convert_frame_buffer(frame.buffer, target);
+                    }
+
                     let handle_id = server.next_id();
                     let frame_info = proto::VideoFrameInfo::from(&frame);
                     let buffer_info = proto::VideoFrameBufferInfo::from(&frame.buffer);
@@ -0,0 +1,41 @@
+// Copyright 2023 LiveKit, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Public end-to-end encryption configuration, passed to `Room::connect` to enable per-track
+//! frame encryption.
+
+pub use crate::room::e2ee::key_provider::KeyProvider;
+pub use crate::room::e2ee::EncryptionType;
+pub use libwebrtc::native::frame_cryptor::EncryptionAlgorithm;
+
+/// Configures end-to-end encryption for a room.
+#[derive(Clone)]
+pub struct E2eeOptions {
+    pub encryption_type: EncryptionType,
+    /// Cipher used by frame cryptors created while these options are active. Selectable per-room
+    /// rather than hardcoded, since different deployments may be required to use a specific
+    /// algorithm (e.g. by platform support or compliance requirements).
+    pub encryption_algorithm: EncryptionAlgorithm,
+    pub key_provider: KeyProvider,
+}
+
+impl Default for E2eeOptions {
+    fn default() -> Self {
+        Self {
+            encryption_type: EncryptionType::Gcm,
+            encryption_algorithm: EncryptionAlgorithm::AesGcm,
+            key_provider: KeyProvider::default(),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright 2023 LiveKit, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use libwebrtc::native::frame_cryptor::KeyProvider as RtcKeyProvider;
+use std::sync::Arc;
+
+/// Holds the shared secret(s) frame cryptors derive per-participant keys from. Cheap to clone;
+/// every clone shares the same underlying native key provider.
+#[derive(Clone)]
+pub struct KeyProvider {
+    pub(crate) handle: Arc<RtcKeyProvider>,
+}
+
+impl KeyProvider {
+    pub fn new() -> Self {
+        Self { handle: Arc::new(RtcKeyProvider::new()) }
+    }
+
+    pub(crate) fn ratchet_key(&self, participant_identity: &str, key_index: i32) -> Vec<u8> {
+        self.handle.ratchet_key(participant_identity, key_index)
+    }
+
+    pub(crate) fn set_key(&self, participant_identity: &str, key_index: i32, key: &[u8]) {
+        self.handle.set_key(participant_identity, key_index, key)
+    }
+}
+
+impl Default for KeyProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
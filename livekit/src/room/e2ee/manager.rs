@@ -19,7 +19,7 @@ use crate::id::{ParticipantIdentity, TrackSid};
 use crate::participant::{LocalParticipant, RemoteParticipant};
 use crate::prelude::{LocalTrack, LocalTrackPublication, RemoteTrack, RemoteTrackPublication};
 use crate::rtc_engine::lk_runtime::LkRuntime;
-use libwebrtc::native::frame_cryptor::{EncryptionAlgorithm, EncryptionState, FrameCryptor};
+use libwebrtc::native::frame_cryptor::{EncryptionState, FrameCryptor};
 use libwebrtc::{rtp_receiver::RtpReceiver, rtp_sender::RtpSender};
 use parking_lot::Mutex;
 use std::collections::HashMap;
@@ -27,10 +27,42 @@ use std::sync::Arc;
 
 type StateChangedHandler = Box<dyn Fn(ParticipantIdentity, EncryptionState) + Send>;
 
+/// Number of consecutive decrypt failures a receiver tolerates before the manager stops
+/// auto-ratcheting its key forward and lets the failure surface as-is through
+/// [`E2eeManager::on_state_changed`].
+const MAX_AUTO_RATCHET_ATTEMPTS: u32 = 8;
+
+/// Number of per-participant key slots tracks are distributed across, mirroring the key ring
+/// size the native key provider maintains per participant.
+const MAX_KEY_INDICES: u64 = 16;
+
+/// FNV-1a 64-bit hash. Unlike `std::collections::hash_map::DefaultHasher`, this algorithm is
+/// fixed and documented-stable across Rust versions, compilers, and platforms, which matters
+/// here: the publisher and every subscriber derive a track's key index independently, possibly
+/// built with different toolchains, and must land on the same value without coordinating over
+/// the wire.
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, &b| (hash ^ b as u64).wrapping_mul(PRIME))
+}
+
+/// Deterministically derives the key index backing `track_sid`'s frame cryptor from the
+/// track_sid alone, so every participant's manager (the publisher and every subscriber) assigns
+/// the same track the same key slot independently, without coordinating over the wire. Using an
+/// arrival-order counter instead would let a publisher and a subscriber disagree whenever
+/// discovery/subscription order differs from publish order.
+fn key_index_for_track(track_sid: &TrackSid) -> i32 {
+    (fnv1a_64(track_sid.to_string().as_bytes()) % MAX_KEY_INDICES) as i32
+}
+
 struct ManagerInner {
     options: Option<E2eeOptions>, // If Some, it means the e2ee was initialized
     enabled: bool,                // Used to enable/disable e2ee
     frame_cryptors: HashMap<(ParticipantIdentity, TrackSid), FrameCryptor>,
+    // Tracks how many times we've auto-ratcheted a receiver's key forward in response to
+    // consecutive decrypt failures, so we eventually give up instead of ratcheting forever.
+    ratchet_attempts: HashMap<(ParticipantIdentity, TrackSid), u32>,
 }
 
 #[derive(Clone)]
@@ -47,6 +79,7 @@ impl E2eeManager {
                 enabled: options.is_some(), // Enabled by default if options is provided
                 options,
                 frame_cryptors: HashMap::new(),
+                ratchet_attempts: HashMap::new(),
             })),
             state_changed: Default::default(),
         }
@@ -89,14 +122,16 @@ impl E2eeManager {
         }
 
         let identity = participant.identity();
+        let track_sid = publication.sid();
         let receiver = track.transceiver().unwrap().receiver();
         let frame_cryptor = self.setup_rtp_receiver(&identity, receiver);
-        self.setup_cryptor(&frame_cryptor);
+        frame_cryptor.set_key_index(key_index_for_track(&track_sid));
+        self.setup_cryptor(&identity, &track_sid, &frame_cryptor);
 
         let mut inner = self.inner.lock();
         inner
             .frame_cryptors
-            .insert((identity, publication.sid()), frame_cryptor.clone());
+            .insert((identity, track_sid), frame_cryptor.clone());
     }
 
     /// Called by the room
@@ -115,25 +150,66 @@ impl E2eeManager {
         }
 
         let identity = participant.identity();
+        let track_sid = publication.sid();
         let sender = track.transceiver().unwrap().sender();
         let frame_cryptor = self.setup_rtp_sender(&identity, sender);
-        self.setup_cryptor(&frame_cryptor);
+        frame_cryptor.set_key_index(key_index_for_track(&track_sid));
+        self.setup_cryptor(&identity, &track_sid, &frame_cryptor);
 
         let mut inner = self.inner.lock();
         inner
             .frame_cryptors
-            .insert((identity, publication.sid()), frame_cryptor.clone());
+            .insert((identity, track_sid), frame_cryptor.clone());
     }
 
-    fn setup_cryptor(&self, frame_cryptor: &FrameCryptor) {
+    fn setup_cryptor(
+        &self,
+        participant_identity: &ParticipantIdentity,
+        track_sid: &TrackSid,
+        frame_cryptor: &FrameCryptor,
+    ) {
+        let manager = self.clone();
+        let track_sid = track_sid.clone();
         let state_changed = self.state_changed.clone();
         frame_cryptor.on_state_change(Some(Box::new(move |participant_identity, state| {
+            let identity: ParticipantIdentity = participant_identity.try_into().unwrap();
+            manager.handle_state_change(&identity, &track_sid, state);
             if let Some(state_changed) = state_changed.lock().as_ref() {
-                state_changed(participant_identity.try_into().unwrap(), state);
+                state_changed(identity, state);
             }
         })));
     }
 
+    /// Auto-ratchets a receiver's key forward on decrypt failures, up to
+    /// [`MAX_AUTO_RATCHET_ATTEMPTS`], so a receiver that fell behind a sender's rotation catches
+    /// back up without the application having to notice and re-key manually.
+    fn handle_state_change(
+        &self,
+        participant_identity: &ParticipantIdentity,
+        track_sid: &TrackSid,
+        state: EncryptionState,
+    ) {
+        let key = (participant_identity.clone(), track_sid.clone());
+        match state {
+            EncryptionState::Ok => {
+                self.inner.lock().ratchet_attempts.remove(&key);
+            }
+            EncryptionState::DecryptionFailed | EncryptionState::MissingKey => {
+                let attempts = {
+                    let mut inner = self.inner.lock();
+                    let attempts = inner.ratchet_attempts.entry(key).or_insert(0);
+                    *attempts += 1;
+                    *attempts
+                };
+
+                if attempts <= MAX_AUTO_RATCHET_ATTEMPTS {
+                    self.ratchet_key(participant_identity, track_sid);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Called by the room
     pub(crate) fn on_local_track_unpublished(
         &self,
@@ -197,7 +273,7 @@ impl E2eeManager {
         let frame_cryptor = FrameCryptor::new_for_rtp_sender(
             LkRuntime::instance().pc_factory(),
             participant_identity.to_string(),
-            EncryptionAlgorithm::AesGcm,
+            options.encryption_algorithm,
             options.key_provider.handle.clone(),
             sender,
         );
@@ -216,7 +292,7 @@ impl E2eeManager {
         let frame_cryptor = FrameCryptor::new_for_rtp_receiver(
             LkRuntime::instance().pc_factory(),
             participant_identity.to_string(),
-            EncryptionAlgorithm::AesGcm,
+            options.encryption_algorithm,
             options.key_provider.handle.clone(),
             receiver,
         );
@@ -224,12 +300,37 @@ impl E2eeManager {
         frame_cryptor
     }
 
+    /// Advances the key for `(participant, track)` by deriving the next key material from the
+    /// current key through the key provider's one-way KDF, so compromising a future key cannot
+    /// recover past frames. Returns the newly derived key, or `None` if e2ee isn't initialized.
+    ///
+    /// Ratchets only the key index assigned to this specific track, not every key the
+    /// participant has. The index is a hash of the track SID modulo [`MAX_KEY_INDICES`], so two
+    /// tracks of the same participant (e.g. mic + screen-share audio) can share an index; those
+    /// tracks share key state, and ratcheting one also ratchets the other.
+    pub fn ratchet_key(
+        &self,
+        participant_identity: &ParticipantIdentity,
+        track_sid: &TrackSid,
+    ) -> Option<Vec<u8>> {
+        let key_provider = self.key_provider()?;
+        Some(key_provider.ratchet_key(&participant_identity.to_string(), key_index_for_track(track_sid)))
+    }
+
+    /// Sets the key at `key_index` for `participant` directly, e.g. to propagate a sender's
+    /// rotated key to a receiver that fell behind instead of waiting for auto-ratcheting.
+    pub fn set_key(&self, participant_identity: &ParticipantIdentity, key_index: i32, key: &[u8]) {
+        if let Some(key_provider) = self.key_provider() {
+            key_provider.set_key(&participant_identity.to_string(), key_index, key);
+        }
+    }
+
     fn remove_frame_cryptor(&self, participant_identity: ParticipantIdentity, track_sid: TrackSid) {
         log::debug!("removing frame cryptor for {}", participant_identity);
 
         let mut inner = self.inner.lock();
-        inner
-            .frame_cryptors
-            .remove(&(participant_identity, track_sid));
+        let key = (participant_identity, track_sid);
+        inner.frame_cryptors.remove(&key);
+        inner.ratchet_attempts.remove(&key);
     }
 }
@@ -0,0 +1,216 @@
+// Copyright 2023 LiveKit, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! In-process fake SFU, enabled by the `test-backend` feature.
+//!
+//! This is a standalone pub/sub registry of simulated rooms/participants: [`TestServer`] creates
+//! or looks up a [`TestRoomHandle`] for a URL, participants [`TestRoomHandle::join`] it and
+//! register a sink via [`TestParticipantHandle::on_event`], and publishing/muting/active-speaker
+//! calls replay the corresponding [`TestRoomEvent`] to every other joined participant. A test
+//! drives this directly (there is no `Room::connect` integration yet, and `TestRoomEvent` only
+//! carries track SIDs, not constructed `RemoteTrack`s or audio/video frames), which lets
+//! downstream crates exercise publish/subscribe/mute fan-out logic without a live server.
+
+#![cfg(feature = "test-backend")]
+
+use crate::id::{ParticipantIdentity, TrackSid};
+use crate::participant::ConnectionQuality;
+use crate::prelude::LocalTrack;
+use crate::ConnectionState;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+/// Events a [`TestRoomHandle`] can replay into a joined participant's registered sink, mirroring the
+/// subset of server events that `Room` reacts to.
+#[derive(Debug, Clone)]
+pub enum TestRoomEvent {
+    TrackPublished { publisher: ParticipantIdentity, track: TrackSid },
+    TrackUnpublished { publisher: ParticipantIdentity, track: TrackSid },
+    TrackMuted { publisher: ParticipantIdentity, track: TrackSid },
+    TrackUnmuted { publisher: ParticipantIdentity, track: TrackSid },
+    ActiveSpeakersChanged { speakers: Vec<ParticipantIdentity> },
+    ConnectionStateChanged { state: ConnectionState },
+    ConnectionQualityChanged { participant: ParticipantIdentity, quality: ConnectionQuality },
+}
+
+/// A single participant as seen by the fake SFU: which tracks it published, and where to
+/// deliver events simulated on its behalf.
+#[derive(Default)]
+struct TestParticipantState {
+    published_tracks: Vec<TrackSid>,
+    sink: Option<Box<dyn Fn(TestRoomEvent) + Send>>,
+}
+
+/// One simulated room, keyed by the URL passed to [`TestServer::create_room`].
+#[derive(Default)]
+struct TestRoomState {
+    participants: HashMap<ParticipantIdentity, TestParticipantState>,
+}
+
+impl TestRoomState {
+    fn broadcast(&self, from: &ParticipantIdentity, event: TestRoomEvent) {
+        for (identity, participant) in &self.participants {
+            if identity == from {
+                continue;
+            }
+            if let Some(sink) = &participant.sink {
+                sink(event.clone());
+            }
+        }
+    }
+}
+
+/// Process-wide registry of [`TestRoomState`]s, keyed by the room URL. This is the in-memory
+/// stand-in for the real signaling server: [`TestServer::create_room`] lazily creates rooms here
+/// and [`TestServer::room`] looks them up. `Room::connect` does not consult it.
+static ROOMS: OnceLock<Mutex<HashMap<String, Arc<Mutex<TestRoomState>>>>> = OnceLock::new();
+
+fn rooms() -> &'static Mutex<HashMap<String, Arc<Mutex<TestRoomState>>>> {
+    ROOMS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Handle to a room created through [`TestServer::create_room`]. Dropping every handle for a
+/// given URL does not tear the room down on its own; call [`TestServer::teardown_room`]
+/// explicitly so tests control the room's lifetime precisely.
+#[derive(Clone)]
+pub struct TestRoomHandle {
+    url: String,
+    state: Arc<Mutex<TestRoomState>>,
+}
+
+impl TestRoomHandle {
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Registers `identity` as a participant of this room. Returns a [`TestParticipantHandle`]
+    /// used to publish tracks and simulate events on its behalf.
+    pub fn join(&self, identity: ParticipantIdentity) -> TestParticipantHandle {
+        self.state.lock().participants.entry(identity.clone()).or_default();
+        TestParticipantHandle { identity, room: self.state.clone() }
+    }
+
+    /// Removes `identity` from the room, unpublishing any tracks it still held.
+    pub fn leave(&self, identity: &ParticipantIdentity) {
+        let mut state = self.state.lock();
+        if let Some(participant) = state.participants.remove(identity) {
+            for track in participant.published_tracks {
+                state.broadcast(
+                    identity,
+                    TestRoomEvent::TrackUnpublished { publisher: identity.clone(), track },
+                );
+            }
+        }
+    }
+
+    /// Simulates the server declaring new active speakers to every joined participant.
+    pub fn set_active_speakers(&self, speakers: Vec<ParticipantIdentity>) {
+        let state = self.state.lock();
+        for participant in state.participants.values() {
+            if let Some(sink) = &participant.sink {
+                sink(TestRoomEvent::ActiveSpeakersChanged { speakers: speakers.clone() });
+            }
+        }
+    }
+}
+
+/// Handle to a single participant inside a [`TestRoomHandle`].
+#[derive(Clone)]
+pub struct TestParticipantHandle {
+    identity: ParticipantIdentity,
+    room: Arc<Mutex<TestRoomState>>,
+}
+
+impl TestParticipantHandle {
+    pub fn identity(&self) -> &ParticipantIdentity {
+        &self.identity
+    }
+
+    /// Registers the callback used to deliver simulated events to this participant.
+    pub fn on_event(&self, f: impl Fn(TestRoomEvent) + Send + 'static) {
+        let mut state = self.room.lock();
+        if let Some(participant) = state.participants.get_mut(&self.identity) {
+            participant.sink = Some(Box::new(f));
+        }
+    }
+
+    /// Publishes `track` on behalf of this participant, delivering a `TrackPublished` event
+    /// carrying its SID to every other participant's registered sink. Returns the SID so the
+    /// caller can correlate it with whatever the sink does in response.
+    pub fn publish_track(&self, track: &LocalTrack) -> TrackSid {
+        let sid = track.sid();
+
+        let mut state = self.room.lock();
+        if let Some(participant) = state.participants.get_mut(&self.identity) {
+            participant.published_tracks.push(sid.clone());
+        }
+        state.broadcast(
+            &self.identity,
+            TestRoomEvent::TrackPublished { publisher: self.identity.clone(), track: sid.clone() },
+        );
+        sid
+    }
+
+    pub fn unpublish_track(&self, track: &LocalTrack) {
+        let sid = track.sid();
+
+        let mut state = self.room.lock();
+        if let Some(participant) = state.participants.get_mut(&self.identity) {
+            participant.published_tracks.retain(|published| published != &sid);
+        }
+        state.broadcast(
+            &self.identity,
+            TestRoomEvent::TrackUnpublished { publisher: self.identity.clone(), track: sid },
+        );
+    }
+
+    pub fn set_muted(&self, track: &LocalTrack, muted: bool) {
+        let sid = track.sid();
+        let state = self.room.lock();
+        let event = if muted {
+            TestRoomEvent::TrackMuted { publisher: self.identity.clone(), track: sid }
+        } else {
+            TestRoomEvent::TrackUnmuted { publisher: self.identity.clone(), track: sid }
+        };
+        state.broadcast(&self.identity, event);
+    }
+}
+
+/// Entry point tests call directly to drive the in-process fake SFU; `Room::connect` does not
+/// go through it. Modeled on the `TestServer` pattern used elsewhere for network-free
+/// integration tests.
+pub struct TestServer;
+
+impl TestServer {
+    /// Creates (or returns the existing) room for `url`.
+    pub fn create_room(url: &str) -> TestRoomHandle {
+        let mut rooms = rooms().lock();
+        let state = rooms.entry(url.to_owned()).or_insert_with(|| Arc::new(Mutex::new(TestRoomState::default())));
+        TestRoomHandle { url: url.to_owned(), state: state.clone() }
+    }
+
+    /// Looks up a room previously created with [`Self::create_room`], if it still exists.
+    pub fn room(url: &str) -> Option<TestRoomHandle> {
+        rooms()
+            .lock()
+            .get(url)
+            .map(|state| TestRoomHandle { url: url.to_owned(), state: state.clone() })
+    }
+
+    /// Removes `url` from the registry, dropping every participant and published track.
+    pub fn teardown_room(url: &str) {
+        rooms().lock().remove(url);
+    }
+}
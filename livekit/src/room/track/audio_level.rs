@@ -0,0 +1,97 @@
+// Copyright 2023 LiveKit, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shared audio level/voice-activity metering backing [`super::local_audio_track::LocalAudioTrack`]
+//! and [`super::remote_track::RemoteAudioTrack`].
+
+/// Level, in dBFS, above which a frame counts towards entering the "speaking" state.
+const SPEAKING_ENTER_DBFS: f32 = -50.0;
+/// Consecutive frames above [`SPEAKING_ENTER_DBFS`] required before raising `speaking`.
+const SPEAKING_ENTER_FRAMES: u32 = 5;
+/// Consecutive frames at/below [`SPEAKING_ENTER_DBFS`] required before clearing `speaking`.
+const SPEAKING_HOLD_OFF_FRAMES: u32 = 25;
+
+/// Per-frame RMS audio level meter with hysteresis-driven voice-activity detection.
+pub(super) struct AudioLevelMeter {
+    pub(super) level: f32,
+    pub(super) speaking: bool,
+    frames_above: u32,
+    frames_below: u32,
+}
+
+impl AudioLevelMeter {
+    pub(super) fn new() -> Self {
+        Self { level: 0.0, speaking: false, frames_above: 0, frames_below: 0 }
+    }
+
+    /// Feeds one frame of interleaved PCM16 samples, returning the updated normalized
+    /// `(level, speaking)` pair. `level` is the frame's RMS amplitude normalized to 0.0-1.0.
+    pub(super) fn push_frame(&mut self, samples: &[i16]) -> (f32, bool) {
+        if samples.is_empty() {
+            return (self.level, self.speaking);
+        }
+
+        let sum_squares: f64 = samples.iter().map(|&s| (s as f64) * (s as f64)).sum();
+        let rms = (sum_squares / samples.len() as f64).sqrt();
+        self.level = (rms / i16::MAX as f64).min(1.0) as f32;
+
+        let dbfs = if self.level > 0.0 { 20.0 * self.level.log10() } else { f32::NEG_INFINITY };
+        if dbfs > SPEAKING_ENTER_DBFS {
+            self.frames_above += 1;
+            self.frames_below = 0;
+        } else {
+            self.frames_below += 1;
+            self.frames_above = 0;
+        }
+
+        if !self.speaking && self.frames_above >= SPEAKING_ENTER_FRAMES {
+            self.speaking = true;
+        } else if self.speaking && self.frames_below >= SPEAKING_HOLD_OFF_FRAMES {
+            self.speaking = false;
+        }
+
+        (self.level, self.speaking)
+    }
+}
+
+pub(super) type AudioLevelHandler = Box<dyn Fn(f32, bool) + Send>;
+
+pub(super) struct AudioLevelState {
+    pub(super) meter: AudioLevelMeter,
+    pub(super) handler: Option<AudioLevelHandler>,
+    pub(super) cadence_frames: u32,
+    pub(super) frames_since_emit: u32,
+}
+
+impl AudioLevelState {
+    pub(super) fn new() -> Self {
+        Self { meter: AudioLevelMeter::new(), handler: None, cadence_frames: 1, frames_since_emit: 0 }
+    }
+
+    /// Feeds one frame of samples into the meter and, if the configured cadence was reached,
+    /// invokes the registered handler with the updated `(level, speaking)` pair.
+    pub(super) fn observe(&mut self, samples: &[i16]) {
+        let (level, speaking) = self.meter.push_frame(samples);
+
+        self.frames_since_emit += 1;
+        if self.frames_since_emit < self.cadence_frames {
+            return;
+        }
+        self.frames_since_emit = 0;
+
+        if let Some(handler) = &self.handler {
+            handler(level, speaking);
+        }
+    }
+}
@@ -12,19 +12,98 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::audio_level::AudioLevelState;
 use super::TrackInner;
 use crate::prelude::*;
 use crate::rtc_engine::lk_runtime::LkRuntime;
+#[cfg(feature = "real-time-audio")]
+use audio_thread_priority::{
+    demote_current_thread_from_real_time, promote_current_thread_to_real_time, AudioThreadGuard,
+};
 use core::panic;
 use libwebrtc::prelude::*;
 use livekit_protocol as proto;
+use parking_lot::Mutex;
+#[cfg(feature = "real-time-audio")]
+use std::cell::RefCell;
 use std::fmt::Debug;
 use std::sync::Arc;
 
+/// Options controlling how a [`LocalAudioTrack`] is created.
+#[derive(Debug, Clone, Copy)]
+pub struct AudioTrackOptions {
+    /// When enabled (requires the `real-time-audio` feature), each thread that calls
+    /// [`LocalAudioTrack::capture_frame`] on the returned track is promoted to real-time
+    /// scheduling on its first such call, using the same approach as the `audio_thread_priority` crate: RTKit (falling
+    /// back to `pthread_setschedparam`) on Linux, `THREAD_TIME_CONSTRAINT_POLICY` on macOS, and
+    /// `AvSetMmThreadCharacteristics` on Windows.
+    ///
+    /// The promotion is tied to the *thread*, not to this track: it happens lazily on every
+    /// thread that pushes a captured frame (typically one dedicated capture thread in a server or
+    /// agent process), and `audio_thread_priority`'s demote call must run on that same OS thread,
+    /// so it can't be triggered from this track's `Drop`. In practice this means the thread stays
+    /// promoted until it exits, even after this track is dropped — on a thread-pool thread that
+    /// gets reused for unrelated work, real-time priority will leak into that work. Only enable
+    /// this on a thread you dedicate to audio capture for the track's lifetime.
+    pub real_time_priority: bool,
+    /// Sample rate of the audio pushed into the track, used to derive the real-time scheduling
+    /// parameters. Ignored when `real_time_priority` is disabled.
+    pub sample_rate: u32,
+}
+
+impl Default for AudioTrackOptions {
+    fn default() -> Self {
+        Self { real_time_priority: false, sample_rate: 48000 }
+    }
+}
+
+/// RAII handle that keeps a thread promoted to real-time scheduling. The thread is demoted back
+/// to its original priority when this handle is dropped.
+#[cfg(feature = "real-time-audio")]
+struct RtPriorityGuard(Option<AudioThreadGuard>);
+
+#[cfg(feature = "real-time-audio")]
+impl RtPriorityGuard {
+    /// Promotes the calling thread, assuming it processes `sample_rate / 100` frames (10ms) at
+    /// `sample_rate`. Returns `None` and logs a warning if the platform refuses the request
+    /// (e.g. missing privileges), so callers can keep running at normal priority.
+    fn promote(sample_rate: u32) -> Option<Self> {
+        let frame_size = sample_rate / 100;
+        match promote_current_thread_to_real_time(frame_size, sample_rate) {
+            Ok(guard) => Some(Self(Some(guard))),
+            Err(err) => {
+                log::warn!("failed to promote audio thread to real-time priority: {:?}", err);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(feature = "real-time-audio")]
+impl Drop for RtPriorityGuard {
+    fn drop(&mut self) {
+        if let Some(guard) = self.0.take() {
+            if let Err(err) = demote_current_thread_from_real_time(guard) {
+                log::warn!("failed to demote audio thread from real-time priority: {:?}", err);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "real-time-audio")]
+thread_local! {
+    // Promoted lazily, on whichever thread first calls `LocalAudioTrack::capture_frame` for a
+    // track that requested real-time priority. Demoted automatically when that thread exits.
+    static CAPTURE_THREAD_RT_PRIORITY: RefCell<Option<RtPriorityGuard>> = const { RefCell::new(None) };
+}
+
 #[derive(Clone)]
 pub struct LocalAudioTrack {
     inner: Arc<TrackInner>,
     source: RtcAudioSource,
+    rt_priority_requested: bool,
+    rt_priority_sample_rate: u32,
+    audio_level: Arc<Mutex<AudioLevelState>>,
 }
 
 impl Debug for LocalAudioTrack {
@@ -47,10 +126,24 @@ impl LocalAudioTrack {
                 MediaStreamTrack::Audio(rtc_track),
             )),
             source,
+            rt_priority_requested: false,
+            rt_priority_sample_rate: 48000,
+            audio_level: Arc::new(Mutex::new(AudioLevelState::new())),
         }
     }
 
     pub fn create_audio_track(name: &str, source: RtcAudioSource) -> LocalAudioTrack {
+        Self::create_audio_track_with_options(name, source, AudioTrackOptions::default())
+    }
+
+    /// Same as [`Self::create_audio_track`], with the ability to opt into real-time scheduling
+    /// via [`AudioTrackOptions::real_time_priority`] (requires the `real-time-audio` feature; see
+    /// its docs for how and when the promotion actually takes effect).
+    pub fn create_audio_track_with_options(
+        name: &str,
+        source: RtcAudioSource,
+        options: AudioTrackOptions,
+    ) -> LocalAudioTrack {
         let rtc_track = match source.clone() {
             #[cfg(not(target_arch = "wasm32"))]
             RtcAudioSource::Native(native_source) => {
@@ -61,7 +154,19 @@ impl LocalAudioTrack {
             }
             _ => panic!("unsupported audio source"),
         };
-        Self::new(name.to_string(), rtc_track, source)
+
+        #[cfg(not(feature = "real-time-audio"))]
+        if options.real_time_priority {
+            log::warn!(
+                "AudioTrackOptions::real_time_priority was requested but the `real-time-audio` \
+                 feature is not enabled; the capture thread will not be promoted"
+            );
+        }
+
+        let mut track = Self::new(name.to_string(), rtc_track, source);
+        track.rt_priority_requested = options.real_time_priority;
+        track.rt_priority_sample_rate = options.sample_rate;
+        track
     }
 
     pub fn sid(&self) -> TrackSid {
@@ -115,6 +220,33 @@ impl LocalAudioTrack {
         self.source.clone()
     }
 
+    /// Pushes one frame of captured audio into this track's `RtcAudioSource`. Prefer this over
+    /// calling [`Self::rtc_source`]'s `capture_frame` directly: on the calling thread's first
+    /// invocation, it also promotes that thread to real-time scheduling when
+    /// [`AudioTrackOptions::real_time_priority`] was requested, since that's the thread that
+    /// actually does the capture work this SDK needs to keep glitch-free. It also feeds the
+    /// frame into the audio level meter backing [`Self::on_audio_level`]/[`Self::audio_level`],
+    /// which otherwise never sees any captured audio.
+    pub fn capture_frame(&self, frame: &AudioFrame<'_>) -> Result<(), RtcError> {
+        #[cfg(feature = "real-time-audio")]
+        if self.rt_priority_requested {
+            CAPTURE_THREAD_RT_PRIORITY.with(|guard| {
+                let mut guard = guard.borrow_mut();
+                if guard.is_none() {
+                    *guard = RtPriorityGuard::promote(self.rt_priority_sample_rate);
+                }
+            });
+        }
+
+        self.observe_audio_frame(&frame.data);
+
+        match &self.source {
+            #[cfg(not(target_arch = "wasm32"))]
+            RtcAudioSource::Native(native_source) => native_source.capture_frame(frame),
+            _ => panic!("unsupported audio source"),
+        }
+    }
+
     pub fn is_remote(&self) -> bool {
         false
     }
@@ -127,6 +259,39 @@ impl LocalAudioTrack {
         *self.inner.events.unmuted.lock() = Some(Box::new(f));
     }
 
+    /// Registers a callback fired with the track's normalized audio level (0.0-1.0) and a
+    /// hysteresis-driven `speaking` flag, at the cadence set by
+    /// [`Self::set_audio_level_cadence`] (every frame by default).
+    pub fn on_audio_level(&self, f: impl Fn(f32, bool) + Send + 'static) {
+        self.audio_level.lock().handler = Some(Box::new(f));
+    }
+
+    /// Sets how many audio frames are processed between [`Self::on_audio_level`] invocations.
+    /// The level and speaking flag are still updated every frame; this only throttles the
+    /// callback cadence.
+    pub fn set_audio_level_cadence(&self, frames: u32) {
+        self.audio_level.lock().cadence_frames = frames.max(1);
+    }
+
+    /// Latest normalized (0.0-1.0) audio level, updated as frames flow through this track.
+    pub fn audio_level(&self) -> f32 {
+        self.audio_level.lock().meter.level
+    }
+
+    /// Whether the hysteresis-driven voice-activity detector currently considers this track
+    /// to be speaking.
+    pub fn is_speaking(&self) -> bool {
+        self.audio_level.lock().meter.speaking
+    }
+
+    /// Feeds one frame of interleaved PCM16 samples into the audio level meter, updating the
+    /// level/speaking state and invoking the [`Self::on_audio_level`] callback according to the
+    /// configured cadence. Called from [`Self::capture_frame`]; frames pushed straight into
+    /// this track's `RtcAudioSource` are not observed.
+    pub(crate) fn observe_audio_frame(&self, samples: &[i16]) {
+        self.audio_level.lock().observe(samples);
+    }
+
     pub(crate) fn transceiver(&self) -> Option<RtpTransceiver> {
         self.inner.info.read().transceiver.clone()
     }
@@ -12,12 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::audio_level::AudioLevelState;
 use super::track_dispatch;
 use super::TrackInner;
 use crate::prelude::*;
+use futures_util::StreamExt;
+use libwebrtc::audio_stream::native::NativeAudioStream;
 use libwebrtc::prelude::*;
 use livekit_protocol as proto;
 use livekit_protocol::enum_dispatch;
+use parking_lot::Mutex;
+use std::fmt::Debug;
 use std::sync::Arc;
 
 #[derive(Clone, Debug)]
@@ -36,6 +41,166 @@ impl RemoteTrack {
             Self::Video(track) => track.rtc_track().into(),
         }
     }
+
+    /// Registers a callback fired with the track's normalized audio level (0.0-1.0) and a
+    /// hysteresis-driven `speaking` flag. No-op on video tracks.
+    pub fn on_audio_level(&self, f: impl Fn(f32, bool) + Send + 'static) {
+        if let Self::Audio(track) = self {
+            track.on_audio_level(f);
+        }
+    }
+
+    /// Latest normalized (0.0-1.0) audio level. Always `0.0` on video tracks.
+    pub fn audio_level(&self) -> f32 {
+        match self {
+            Self::Audio(track) => track.audio_level(),
+            Self::Video(_) => 0.0,
+        }
+    }
+
+    /// Whether the hysteresis-driven voice-activity detector currently considers this track to
+    /// be speaking. Always `false` on video tracks.
+    pub fn is_speaking(&self) -> bool {
+        match self {
+            Self::Audio(track) => track.is_speaking(),
+            Self::Video(_) => false,
+        }
+    }
+}
+
+/// A remote participant's published audio track, received over the peer connection.
+#[derive(Clone)]
+pub struct RemoteAudioTrack {
+    inner: Arc<TrackInner>,
+    rtc_track: RtcAudioTrack,
+    audio_level: Arc<Mutex<AudioLevelState>>,
+}
+
+impl Debug for RemoteAudioTrack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoteAudioTrack")
+            .field("sid", &self.sid())
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
+impl RemoteAudioTrack {
+    pub(crate) fn new(sid: TrackSid, name: String, rtc_track: RtcAudioTrack) -> Self {
+        let track = Self {
+            inner: Arc::new(super::new_inner(
+                sid,
+                name,
+                TrackKind::Audio,
+                MediaStreamTrack::Audio(rtc_track.clone()),
+            )),
+            rtc_track,
+            audio_level: Arc::new(Mutex::new(AudioLevelState::new())),
+        };
+        track.spawn_audio_level_task();
+        track
+    }
+
+    /// Spawns a background task that taps the native audio frames flowing through the
+    /// underlying `RtcAudioTrack` and feeds them into the audio level meter backing
+    /// [`Self::on_audio_level`]/[`Self::audio_level`], mirroring how
+    /// [`super::local_audio_track::LocalAudioTrack`] observes captured frames on the publish
+    /// side. Runs for the lifetime of the track: it stops when the stream ends, or at the first
+    /// frame after every clone of the track has been dropped.
+    fn spawn_audio_level_task(&self) {
+        let mut audio_stream = NativeAudioStream::new(self.rtc_track.clone());
+        let audio_level = Arc::downgrade(&self.audio_level);
+
+        tokio::spawn(async move {
+            while let Some(frame) = audio_stream.next().await {
+                let Some(audio_level) = audio_level.upgrade() else {
+                    break;
+                };
+                audio_level.lock().observe(frame.data.as_ref());
+            }
+        });
+    }
+
+    pub fn sid(&self) -> TrackSid {
+        self.inner.info.read().sid.clone()
+    }
+
+    pub fn name(&self) -> String {
+        self.inner.info.read().name.clone()
+    }
+
+    pub fn kind(&self) -> TrackKind {
+        self.inner.info.read().kind
+    }
+
+    pub fn stream_state(&self) -> StreamState {
+        self.inner.info.read().stream_state
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.inner.info.read().muted
+    }
+
+    pub fn mute(&self) {
+        super::set_muted(&self.inner, &Track::RemoteAudio(self.clone()), true);
+    }
+
+    pub fn unmute(&self) {
+        super::set_muted(&self.inner, &Track::RemoteAudio(self.clone()), false);
+    }
+
+    pub fn rtc_track(&self) -> RtcAudioTrack {
+        self.rtc_track.clone()
+    }
+
+    pub fn is_remote(&self) -> bool {
+        true
+    }
+
+    pub fn on_muted(&self, f: impl Fn(Track) + Send + 'static) {
+        *self.inner.events.muted.lock() = Some(Box::new(f));
+    }
+
+    pub fn on_unmuted(&self, f: impl Fn(Track) + Send + 'static) {
+        *self.inner.events.unmuted.lock() = Some(Box::new(f));
+    }
+
+    /// Registers a callback fired with the track's normalized audio level (0.0-1.0) and a
+    /// hysteresis-driven `speaking` flag, at the cadence set by
+    /// [`Self::set_audio_level_cadence`] (every frame by default).
+    pub fn on_audio_level(&self, f: impl Fn(f32, bool) + Send + 'static) {
+        self.audio_level.lock().handler = Some(Box::new(f));
+    }
+
+    /// Sets how many audio frames are processed between [`Self::on_audio_level`] invocations.
+    /// The level and speaking flag are still updated every frame; this only throttles the
+    /// callback cadence.
+    pub fn set_audio_level_cadence(&self, frames: u32) {
+        self.audio_level.lock().cadence_frames = frames.max(1);
+    }
+
+    /// Latest normalized (0.0-1.0) audio level, updated as frames arrive from the remote peer.
+    pub fn audio_level(&self) -> f32 {
+        self.audio_level.lock().meter.level
+    }
+
+    /// Whether the hysteresis-driven voice-activity detector currently considers this track to
+    /// be speaking.
+    pub fn is_speaking(&self) -> bool {
+        self.audio_level.lock().meter.speaking
+    }
+
+    pub(crate) fn transceiver(&self) -> Option<RtpTransceiver> {
+        self.inner.info.read().transceiver.clone()
+    }
+
+    pub(crate) fn set_transceiver(&self, transceiver: Option<RtpTransceiver>) {
+        self.inner.info.write().transceiver = transceiver;
+    }
+
+    pub(crate) fn update_info(&self, info: proto::TrackInfo) {
+        update_info(&self.inner, &Track::RemoteAudio(self.clone()), info);
+    }
 }
 
 pub(super) fn update_info(inner: &Arc<TrackInner>, track: &Track, new_info: proto::TrackInfo) {